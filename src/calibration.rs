@@ -0,0 +1,149 @@
+//! Measures this machine's raw hardware throughput so benchmark durations collected on
+//! different machines can be normalized against each other before being compared.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    io::Write,
+    time::{Duration, Instant},
+};
+
+/// The wall-clock budget each calibration sub-benchmark is allowed to run for, repeating its
+/// workload internally to reduce variance.
+const CALIBRATION_BUDGET: Duration = Duration::from_millis(200);
+
+/// Raw hardware throughput scores used to normalize benchmark durations across machines.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HardwareScore {
+    /// Raw integer/float CPU throughput, in operations per second.
+    pub cpu_ops_per_sec: f64,
+    /// Sequential memory read/write bandwidth, in GiB/s.
+    pub memory_gib_per_sec: f64,
+    /// Sequential disk write+fsync throughput, in GiB/s.
+    pub disk_gib_per_sec: f64,
+}
+
+/// A neutral score, used when a stored baseline predates hardware calibration; scaling by it
+/// is a no-op, so old baselines are compared without normalization rather than failing to load.
+impl Default for HardwareScore {
+    fn default() -> Self {
+        Self {
+            cpu_ops_per_sec: 1.0,
+            memory_gib_per_sec: 1.0,
+            disk_gib_per_sec: 1.0,
+        }
+    }
+}
+
+impl HardwareScore {
+    /// Measures this machine's raw CPU, memory, and disk throughput.
+    pub fn measure() -> Self {
+        Self {
+            cpu_ops_per_sec: measure_cpu_throughput(),
+            memory_gib_per_sec: measure_memory_bandwidth(),
+            disk_gib_per_sec: measure_disk_write_speed(),
+        }
+    }
+
+    /// Returns the factor by which a duration measured on this machine should be scaled to be
+    /// comparable with one measured on a `reference` machine, averaged across the three scores.
+    /// A machine that is twice as fast as the reference takes half the time, so its durations
+    /// must be scaled up by roughly `self_score / reference_score` to be comparable.
+    pub fn ratio_to(&self, reference: &HardwareScore) -> f64 {
+        if *self == Self::default() || *reference == Self::default() {
+            return 1.0;
+        }
+
+        (component_ratio(self.cpu_ops_per_sec, reference.cpu_ops_per_sec)
+            + component_ratio(self.memory_gib_per_sec, reference.memory_gib_per_sec)
+            + component_ratio(self.disk_gib_per_sec, reference.disk_gib_per_sec))
+            / 3.0
+    }
+
+    /// Normalizes a `raw_duration` measured on this machine to a `reference` machine:
+    /// `raw_duration * local_score / reference_score`.
+    pub fn normalize(&self, raw_duration: Duration, reference: &HardwareScore) -> Duration {
+        Duration::from_secs_f64(raw_duration.as_secs_f64() * self.ratio_to(reference))
+    }
+}
+
+/// Returns `local / reference`, falling back to a neutral `1.0` if either score is non-positive
+/// or the result isn't finite, so a failed or zero calibration measurement doesn't poison the
+/// whole ratio with `NaN` or `inf`.
+fn component_ratio(local: f64, reference: f64) -> f64 {
+    if local <= 0.0 || reference <= 0.0 {
+        return 1.0;
+    }
+
+    let ratio = local / reference;
+    if ratio.is_finite() { ratio } else { 1.0 }
+}
+
+/// Repeatedly runs a fixed integer/float workload for `CALIBRATION_BUDGET`, returning operations/sec.
+fn measure_cpu_throughput() -> f64 {
+    let start = Instant::now();
+    let mut operations: u64 = 0;
+    let mut accumulator: f64 = 1.0;
+
+    while start.elapsed() < CALIBRATION_BUDGET {
+        for _ in 0..10_000 {
+            accumulator = (accumulator * 1.000_000_1 + 1.0).sin();
+            operations += 1;
+        }
+    }
+
+    // Prevent the optimizer from eliding the workload entirely.
+    std::hint::black_box(accumulator);
+
+    operations as f64 / start.elapsed().as_secs_f64()
+}
+
+/// Repeatedly performs a large sequential read+write over an in-memory buffer for
+/// `CALIBRATION_BUDGET`, returning throughput in GiB/s.
+fn measure_memory_bandwidth() -> f64 {
+    const BUFFER_LEN: usize = 64 * 1024 * 1024;
+
+    let mut buffer = vec![0_u8; BUFFER_LEN];
+    let start = Instant::now();
+    let mut bytes_processed: u64 = 0;
+
+    while start.elapsed() < CALIBRATION_BUDGET {
+        for byte in &mut buffer {
+            *byte = byte.wrapping_add(1);
+        }
+        bytes_processed += BUFFER_LEN as u64;
+    }
+
+    std::hint::black_box(&buffer);
+
+    let gib_processed = bytes_processed as f64 / (1024.0 * 1024.0 * 1024.0);
+    gib_processed / start.elapsed().as_secs_f64()
+}
+
+/// Repeatedly writes and fsyncs a fixed-size temporary file for `CALIBRATION_BUDGET`, returning
+/// throughput in GiB/s.
+fn measure_disk_write_speed() -> f64 {
+    const CHUNK_LEN: usize = 4 * 1024 * 1024;
+
+    let chunk = vec![0_u8; CHUNK_LEN];
+    let path = std::env::temp_dir().join(format!("forc-perf-disk-calibration-{}", std::process::id()));
+
+    let start = Instant::now();
+    let mut bytes_written: u64 = 0;
+
+    while start.elapsed() < CALIBRATION_BUDGET {
+        let Ok(mut file) = std::fs::File::create(&path) else {
+            break;
+        };
+
+        if file.write_all(&chunk).is_err() || file.sync_all().is_err() {
+            break;
+        }
+
+        bytes_written += CHUNK_LEN as u64;
+    }
+
+    let _ = std::fs::remove_file(&path);
+
+    let gib_written = bytes_written as f64 / (1024.0 * 1024.0 * 1024.0);
+    gib_written / start.elapsed().as_secs_f64()
+}
@@ -0,0 +1,101 @@
+//! Command-line arguments and `forc-perf.toml` file configuration, merged into the runtime
+//! configuration the rest of the tool runs with.
+
+use clap::Parser;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+/// Benchmarks `forc build` across a directory of Sway projects.
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+pub struct Cli {
+    /// Path to the `forc` binary to benchmark. Defaults to `forc` on `PATH`.
+    #[arg(long)]
+    pub forc_path: Option<PathBuf>,
+
+    /// Directory containing the benchmark projects.
+    #[arg(long)]
+    pub tests_dir: Option<PathBuf>,
+
+    /// Path to write the JSON benchmark results to.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+
+    /// Extra argument passed through to every `forc build` invocation. May be given multiple times.
+    #[arg(long = "build-arg")]
+    pub build_args: Vec<String>,
+
+    /// Only run benchmarks whose name contains this substring.
+    #[arg(long)]
+    pub filter: Option<String>,
+
+    /// Path to a `forc-perf.toml` configuration file.
+    #[arg(long, default_value = "forc-perf.toml")]
+    pub config: PathBuf,
+}
+
+/// File-based configuration for `forc-perf`, merged with any CLI arguments.
+#[derive(Debug, Default, Deserialize)]
+pub struct FileConfig {
+    pub forc_path: Option<PathBuf>,
+    pub tests_dir: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+    #[serde(default)]
+    pub build_args: Vec<String>,
+    pub filter: Option<String>,
+}
+
+impl FileConfig {
+    /// Loads the configuration from `path`, returning the default (empty) configuration if the
+    /// file doesn't exist.
+    pub fn load(path: &std::path::Path) -> crate::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = std::fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+}
+
+/// The fully-resolved runtime configuration, after merging CLI arguments over the config file
+/// and falling back to defaults usable outside the author's filesystem.
+#[derive(Debug, Clone)]
+pub struct RunConfig {
+    pub forc_path: PathBuf,
+    pub tests_dir: PathBuf,
+    pub output: PathBuf,
+    pub build_args: Vec<String>,
+    pub filter: Option<String>,
+}
+
+impl RunConfig {
+    /// Resolves the runtime configuration from `cli` arguments and an optional `file` config,
+    /// with CLI arguments taking precedence over the config file, which takes precedence over
+    /// the defaults.
+    pub fn resolve(cli: &Cli, file: &FileConfig) -> Self {
+        Self {
+            forc_path: cli
+                .forc_path
+                .clone()
+                .or_else(|| file.forc_path.clone())
+                .unwrap_or_else(|| PathBuf::from("forc")),
+            tests_dir: cli
+                .tests_dir
+                .clone()
+                .or_else(|| file.tests_dir.clone())
+                .unwrap_or_else(|| PathBuf::from("./tests/")),
+            output: cli
+                .output
+                .clone()
+                .or_else(|| file.output.clone())
+                .unwrap_or_else(|| PathBuf::from("./benchmarks.json")),
+            build_args: if cli.build_args.is_empty() {
+                file.build_args.clone()
+            } else {
+                cli.build_args.clone()
+            },
+            filter: cli.filter.clone().or_else(|| file.filter.clone()),
+        }
+    }
+}
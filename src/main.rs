@@ -3,79 +3,162 @@
 
 use std::time::Instant;
 
+pub mod calibration;
+pub mod cli;
+pub mod report;
+pub mod stats;
 pub mod types;
 pub mod utils;
 
+use clap::Parser;
+use report::Reporter;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-const BENCHMARKS_FILE_PATH: &str = "./benchmarks.json";
+const REPORT_FILE_PATH: &str = "./benchmark-report.md";
 
 fn main() -> Result<()> {
     // Get the program-specific epoch
     let epoch = Instant::now();
 
+    // Parse CLI arguments and merge them over the `forc-perf.toml` config file, if any
+    let cli = cli::Cli::parse();
+    let file_config = cli::FileConfig::load(&cli.config)?;
+    let run_config = cli::RunConfig::resolve(&cli, &file_config);
+
     // Get the system specifications
     let system_specs = utils::system_specs()?;
 
+    // Measure this machine's raw hardware throughput, used to normalize durations when comparing
+    // against a baseline collected on a different machine
+    let hardware_score = calibration::HardwareScore::measure();
+
     // Create a mutable array of new benchmarks to be performed
-    let mut benchmarks = utils::generate_benchmarks("./tests/")?;
+    let mut benchmarks = utils::generate_benchmarks(&run_config.tests_dir, run_config.filter.as_deref())?;
+
+    // Use the default sampling configuration, overridden with the resolved forc path and build args
+    let config = types::BenchmarkConfig {
+        forc_path: run_config.forc_path.clone(),
+        build_args: run_config.build_args.clone(),
+        ..types::BenchmarkConfig::default()
+    };
+
+    // Load the previous run's results, if any, to use as a regression baseline
+    let baseline = load_baseline(&run_config.output)?;
 
     // Get the start time of the entire benchmarking process
     let start_time = std::time::Instant::now();
 
     // Run all of the benchmarks
     for benchmark in &mut benchmarks {
-        benchmark.run(&epoch);
+        benchmark.run(&epoch, &config);
     }
 
     // Get the end time of the entire benchmarking process
     let end_time = std::time::Instant::now();
 
-    // Print the benchmark results
-    print_benchmarks(start_time, end_time, &benchmarks);
+    // Print the total time the benchmarking process took
+    println!(
+        "Benchmarking took {:?} in total:",
+        end_time.duration_since(start_time),
+    );
 
-    // Store the benchmark results
-    store_benchmarks(&types::Benchmarks {
+    // Print a summary of how many benchmarks ran and how many failed
+    let failed_benchmarks: Vec<&types::Benchmark> = benchmarks
+        .iter()
+        .filter(|benchmark| matches!(benchmark.status, types::BenchmarkStatus::Failed { .. }))
+        .collect();
+
+    println!(
+        "{} of {} benchmarks failed:",
+        failed_benchmarks.len(),
+        benchmarks.len(),
+    );
+
+    for benchmark in &failed_benchmarks {
+        if let types::BenchmarkStatus::Failed { code, stderr_tail } = &benchmark.status {
+            println!("    Benchmark \"{}\" failed with code {code:?}:", benchmark.name);
+            for line in stderr_tail {
+                println!("        {line}");
+            }
+        }
+    }
+
+    let any_benchmark_failed = !failed_benchmarks.is_empty();
+
+    // Render a report of the results, choosing the output format at runtime
+    match report_format() {
+        report::Format::Table => print!("{}", report::TableReporter.render(&benchmarks)),
+        report::Format::Markdown => report::MarkdownReporter.write_to_file(&benchmarks, REPORT_FILE_PATH)?,
+    }
+
+    let benchmarks = types::Benchmarks {
         system_specs,
         benchmarks,
-    })?;
+        hardware_score,
+    };
+
+    // Compare against the baseline, if one was loaded, and fail the run if anything regressed
+    let has_regression = if let Some(baseline) = &baseline {
+        let report = benchmarks.compare(baseline, &config);
+        print_comparison_report(&report);
+        report.has_regression()
+    } else {
+        false
+    };
+
+    // Store the benchmark results
+    store_benchmarks(&benchmarks, &run_config.output)?;
+
+    if has_regression || any_benchmark_failed {
+        std::process::exit(1);
+    }
 
     Ok(())
 }
 
+/// Determines the report output format from the `FORC_PERF_REPORT_FORMAT` environment variable,
+/// defaulting to a terminal table.
+fn report_format() -> report::Format {
+    match std::env::var("FORC_PERF_REPORT_FORMAT") {
+        Ok(value) if value.eq_ignore_ascii_case("markdown") => report::Format::Markdown,
+        _ => report::Format::Table,
+    }
+}
+
+/// Load the previous run's results from `path`, if the file exists, to use as a regression baseline.
+fn load_baseline(path: &std::path::Path) -> Result<Option<types::Benchmarks>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let benchmarks_json_string = std::fs::read_to_string(path)?;
+    Ok(Some(serde_json::from_str(&benchmarks_json_string)?))
+}
+
 /// Store the benchmark results in a file
-fn store_benchmarks(benchmarks: &types::Benchmarks) -> Result<()> {
+fn store_benchmarks(benchmarks: &types::Benchmarks, path: &std::path::Path) -> Result<()> {
     let benchmarks_json_string = serde_json::to_string_pretty(&benchmarks)?;
-    std::fs::write(BENCHMARKS_FILE_PATH, benchmarks_json_string)?;
+    std::fs::write(path, benchmarks_json_string)?;
     Ok(())
 }
 
-/// Print the benchmark results
+/// Print a per-benchmark, per-phase summary of how results changed relative to the baseline.
 /// This is only used only for debugging purposes
-fn print_benchmarks(start_time: Instant, end_time: Instant, benchmarks: &Vec<types::Benchmark>) {
-    // Display the benchmark results
-    println!(
-        "Benchmarking took {:?} in total:",
-        end_time.duration_since(start_time),
-    );
+fn print_comparison_report(report: &types::ComparisonReport) {
+    println!("Comparison against baseline:");
 
-    for benchmark in benchmarks {
+    for benchmark in &report.benchmarks {
         println!(
-            "    Benchmark \"{}\" took {:?} in total:",
-            benchmark.name,
-            benchmark.end_time.unwrap(),
+            "    Benchmark \"{}\" bytecode size change: {:?} ({:?})",
+            benchmark.name, benchmark.bytecode_size_change_percent, benchmark.bytecode_size_classification,
         );
 
         for phase in &benchmark.phases {
             println!(
-                "        Phase \"{}\" took {:?} in total:",
-                phase.name,
-                phase.end_time.unwrap(),
+                "        Phase \"{}\" duration change: {:?} ({:?})",
+                phase.name, phase.duration_change_percent, phase.duration_classification,
             );
         }
-        println!(
-            "            {}",
-            format!("{:#?}", benchmark.frames).replace('\n', "\n            "),
-        );
     }
 }
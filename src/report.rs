@@ -0,0 +1,186 @@
+//! Renders a set of benchmark results into a human- or CI-readable summary, as an alternative
+//! to the raw `{:#?}`-formatted frame dump used for debugging.
+
+use crate::types::Benchmark;
+
+const COLUMNS: [&str; 8] = [
+    "Benchmark",
+    "Phase",
+    "Duration",
+    "Bytecode Size",
+    "Peak Memory",
+    "Mean CPU",
+    "Bytes Read",
+    "Bytes Written",
+];
+
+/// Renders a set of benchmark results into a summary string.
+pub trait Reporter {
+    /// Renders `benchmarks` into a summary string.
+    fn render(&self, benchmarks: &[Benchmark]) -> String;
+}
+
+/// The output format used when rendering a benchmark report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Markdown,
+}
+
+/// Renders benchmarks as a pretty, column-aligned table suitable for a terminal.
+pub struct TableReporter;
+
+impl Reporter for TableReporter {
+    fn render(&self, benchmarks: &[Benchmark]) -> String {
+        let rows = rows(benchmarks);
+        let widths = column_widths(&rows);
+
+        let mut output = String::new();
+        output.push_str(&render_row(&COLUMNS.map(String::from), &widths));
+        output.push_str(&render_rule(&widths));
+
+        for row in &rows {
+            output.push_str(&render_row(&row.columns(), &widths));
+        }
+
+        output
+    }
+}
+
+/// Renders benchmarks as a GitHub-flavored Markdown table suitable for posting as a PR comment.
+pub struct MarkdownReporter;
+
+impl MarkdownReporter {
+    /// Renders `benchmarks` and writes the result to `path`, for posting as a PR comment.
+    pub fn write_to_file<P: AsRef<std::path::Path>>(
+        &self,
+        benchmarks: &[Benchmark],
+        path: P,
+    ) -> std::io::Result<()> {
+        std::fs::write(path, self.render(benchmarks))
+    }
+}
+
+impl Reporter for MarkdownReporter {
+    fn render(&self, benchmarks: &[Benchmark]) -> String {
+        let rows = rows(benchmarks);
+
+        let mut output = String::new();
+        output.push_str(&render_markdown_row(&COLUMNS.map(String::from)));
+        output.push_str(&render_markdown_rule(COLUMNS.len()));
+
+        for row in &rows {
+            output.push_str(&render_markdown_row(&row.columns()));
+        }
+
+        output
+    }
+}
+
+/// One rendered row of the summary table.
+struct Row {
+    benchmark: String,
+    phase: String,
+    duration: String,
+    bytecode_size: String,
+    peak_memory: String,
+    mean_cpu_usage: String,
+    bytes_read: String,
+    bytes_written: String,
+}
+
+impl Row {
+    fn columns(&self) -> [String; 8] {
+        [
+            self.benchmark.clone(),
+            self.phase.clone(),
+            self.duration.clone(),
+            self.bytecode_size.clone(),
+            self.peak_memory.clone(),
+            self.mean_cpu_usage.clone(),
+            self.bytes_read.clone(),
+            self.bytes_written.clone(),
+        ]
+    }
+}
+
+/// Builds one row per benchmark phase, from the per-phase resource usage aggregates already
+/// computed on each `BenchmarkPhase`.
+fn rows(benchmarks: &[Benchmark]) -> Vec<Row> {
+    benchmarks.iter().flat_map(benchmark_rows).collect()
+}
+
+fn benchmark_rows(benchmark: &Benchmark) -> Vec<Row> {
+    let bytecode_size = benchmark
+        .bytecode_size
+        .map_or_else(|| "-".to_string(), |size| size.to_string());
+
+    benchmark
+        .phases
+        .iter()
+        .map(|phase| Row {
+            benchmark: benchmark.name.clone(),
+            phase: phase.name.clone(),
+            duration: phase
+                .mean
+                .map_or_else(|| "-".to_string(), |duration| format!("{duration:?}")),
+            bytecode_size: bytecode_size.clone(),
+            peak_memory: phase
+                .peak_memory_usage
+                .map_or_else(|| "-".to_string(), |bytes| format!("{bytes} B")),
+            mean_cpu_usage: phase
+                .mean_cpu_usage
+                .map_or_else(|| "-".to_string(), |cpu| format!("{cpu:.1}%")),
+            bytes_read: phase
+                .bytes_read
+                .map_or_else(|| "-".to_string(), |bytes| format!("{bytes} B")),
+            bytes_written: phase
+                .bytes_written
+                .map_or_else(|| "-".to_string(), |bytes| format!("{bytes} B")),
+        })
+        .collect()
+}
+
+/// Computes the display width of each column across the header and all `rows`.
+fn column_widths(rows: &[Row]) -> [usize; 8] {
+    let mut widths = COLUMNS.map(str::len);
+
+    for row in rows {
+        for (width, column) in widths.iter_mut().zip(row.columns()) {
+            *width = (*width).max(column.len());
+        }
+    }
+
+    widths
+}
+
+fn render_row(columns: &[String; 8], widths: &[usize; 8]) -> String {
+    let mut line = String::new();
+
+    for (column, width) in columns.iter().zip(widths) {
+        line.push_str(&format!("{column:<width$}  "));
+    }
+
+    line.push('\n');
+    line
+}
+
+fn render_rule(widths: &[usize; 8]) -> String {
+    let mut line = String::new();
+
+    for width in widths {
+        line.push_str(&"-".repeat(*width));
+        line.push_str("  ");
+    }
+
+    line.push('\n');
+    line
+}
+
+fn render_markdown_row(columns: &[String; 8]) -> String {
+    format!("| {} |\n", columns.join(" | "))
+}
+
+fn render_markdown_rule(column_count: usize) -> String {
+    format!("|{}\n", " --- |".repeat(column_count))
+}
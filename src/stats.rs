@@ -0,0 +1,117 @@
+//! Small statistics helpers used to turn raw per-sample measurements into the
+//! aggregates (`mean`, `std_dev`, confidence intervals, outlier classification) reported
+//! alongside a benchmark's phases.
+
+use rand::Rng;
+
+/// The bounds of a bootstrapped confidence interval.
+#[derive(Debug, Clone, Copy)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// How far outside the interquartile range a value falls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlierSeverity {
+    None,
+    Mild,
+    Severe,
+}
+
+/// Computes the arithmetic mean of `samples`.
+pub fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+/// Computes the sample standard deviation of `samples`, or `0.0` if fewer than two samples are given.
+pub fn std_dev(samples: &[f64]) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = mean(samples);
+    let variance =
+        samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / (samples.len() - 1) as f64;
+
+    variance.sqrt()
+}
+
+/// Computes the median of `samples`.
+pub fn median(samples: &[f64]) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Computes the first and third quartiles of an already-sorted slice, using linear interpolation.
+fn quartiles(sorted: &[f64]) -> (f64, f64) {
+    let percentile = |p: f64| -> f64 {
+        let index = p * (sorted.len() - 1) as f64;
+        let lower = index.floor() as usize;
+        let upper = index.ceil() as usize;
+
+        if lower == upper {
+            sorted[lower]
+        } else {
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (index - lower as f64)
+        }
+    };
+
+    (percentile(0.25), percentile(0.75))
+}
+
+/// Classifies `value` as a mild or severe outlier relative to the interquartile range of `samples`,
+/// flagging values beyond 1.5x/3x the IQR from the nearest quartile.
+pub fn classify_outlier(value: f64, samples: &[f64]) -> OutlierSeverity {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let (q1, q3) = quartiles(&sorted);
+    let iqr = q3 - q1;
+
+    if value < q1 - 3.0 * iqr || value > q3 + 3.0 * iqr {
+        OutlierSeverity::Severe
+    } else if value < q1 - 1.5 * iqr || value > q3 + 1.5 * iqr {
+        OutlierSeverity::Mild
+    } else {
+        OutlierSeverity::None
+    }
+}
+
+/// Bootstraps a confidence interval for the mean of `samples` at the given `confidence_level`,
+/// resampling with replacement `nresamples` times.
+pub fn bootstrap_confidence_interval(
+    samples: &[f64],
+    confidence_level: f64,
+    nresamples: usize,
+) -> ConfidenceInterval {
+    let mut rng = rand::thread_rng();
+    let mut resample_means = Vec::with_capacity(nresamples);
+
+    for _ in 0..nresamples {
+        let resample_mean = (0..samples.len())
+            .map(|_| samples[rng.gen_range(0..samples.len())])
+            .sum::<f64>()
+            / samples.len() as f64;
+        resample_means.push(resample_mean);
+    }
+
+    resample_means.sort_by(|a, b| a.total_cmp(b));
+
+    let alpha = (1.0 - confidence_level) / 2.0;
+    let last_index = resample_means.len() - 1;
+    let lower_index = ((alpha * resample_means.len() as f64) as usize).min(last_index);
+    let upper_index = (((1.0 - alpha) * resample_means.len() as f64) as usize).min(last_index);
+
+    ConfidenceInterval {
+        lower: resample_means[lower_index],
+        upper: resample_means[upper_index],
+    }
+}
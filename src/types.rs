@@ -1,6 +1,8 @@
+use crate::stats;
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::VecDeque,
     io::BufRead,
     path::PathBuf,
     process::{Child, Command, Stdio},
@@ -8,15 +10,224 @@ use std::{
     time::{Duration, Instant},
 };
 
+/// The number of trailing stderr lines retained from a failed `forc build` invocation.
+const STDERR_TAIL_LINES: usize = 20;
+
+/// Configuration controlling how many samples are collected per benchmark and how the
+/// resulting statistics are derived, modeled on criterion-style sampling config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkConfig {
+    /// The number of discarded runs performed before measured sampling begins.
+    pub warm_up_runs: usize,
+    /// The number of measured runs collected per benchmark.
+    pub sample_size: usize,
+    /// The confidence level used when bootstrapping each phase's confidence interval (e.g. `0.95`).
+    pub confidence_level: f64,
+    /// The number of resamples used when bootstrapping a confidence interval.
+    pub nresamples: usize,
+    /// The relative change (as a fraction) below which a change is reported as noise.
+    pub noise_threshold: f64,
+    /// The significance level for deciding whether a regression is statistically real. No code
+    /// reads it: `Benchmarks::compare` decides regressions from `noise_threshold` and the
+    /// baseline's confidence interval.
+    pub significance_level: f64,
+    /// The path to the `forc` binary to benchmark.
+    pub forc_path: PathBuf,
+    /// Extra arguments passed through to every `forc build` invocation.
+    pub build_args: Vec<String>,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            warm_up_runs: 3,
+            sample_size: 10,
+            confidence_level: 0.95,
+            nresamples: 100_000,
+            noise_threshold: 0.05,
+            significance_level: 0.05,
+            forc_path: PathBuf::from("forc"),
+            build_args: vec![],
+        }
+    }
+}
+
 /// A collection of benchmarks and system specifications.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Benchmarks {
     pub system_specs: SystemSpecs,
     pub benchmarks: Vec<Benchmark>,
+    /// This machine's raw hardware throughput, used to normalize durations when comparing
+    /// against a baseline collected on a different machine. Defaults to a neutral score so
+    /// baselines stored before this field existed still deserialize.
+    #[serde(default)]
+    pub hardware_score: crate::calibration::HardwareScore,
+}
+
+impl Benchmarks {
+    /// Compares these results against a `baseline`, classifying each benchmark's bytecode size
+    /// and each phase's duration as improved, regressed, or unchanged relative to
+    /// `config.noise_threshold`. Phase durations are normalized by each run's `hardware_score`
+    /// before comparing, so results from heterogeneous machines remain comparable. When the
+    /// baseline phase has a confidence interval, a duration is only classified as regressed if
+    /// the new mean additionally falls outside of it.
+    pub fn compare(&self, baseline: &Benchmarks, config: &BenchmarkConfig) -> ComparisonReport {
+        let hardware_ratio = self.hardware_score.ratio_to(&baseline.hardware_score);
+
+        let benchmarks = self
+            .benchmarks
+            .iter()
+            .map(|benchmark| {
+                let baseline_benchmark = baseline.benchmarks.iter().find(|b| b.name == benchmark.name);
+
+                let (bytecode_size_change_percent, bytecode_size_classification) = match (
+                    benchmark.bytecode_size,
+                    baseline_benchmark.and_then(|b| b.bytecode_size),
+                ) {
+                    (Some(current), Some(previous)) if previous != 0 => {
+                        let change_percent = percent_change(previous as f64, current as f64);
+                        (Some(change_percent), classify_change(change_percent, config.noise_threshold))
+                    }
+                    _ => (None, Classification::Unchanged),
+                };
+
+                let phases = benchmark
+                    .phases
+                    .iter()
+                    .map(|phase| {
+                        let baseline_phase =
+                            baseline_benchmark.and_then(|b| b.phases.iter().find(|p| p.name == phase.name));
+
+                        compare_phase(phase, baseline_phase, config, hardware_ratio)
+                    })
+                    .collect();
+
+                BenchmarkComparison {
+                    name: benchmark.name.clone(),
+                    bytecode_size_change_percent,
+                    bytecode_size_classification,
+                    phases,
+                }
+            })
+            .collect();
+
+        ComparisonReport { benchmarks }
+    }
+}
+
+/// Compares a single phase's mean duration against its `baseline` counterpart, after scaling
+/// the current duration by `hardware_ratio` to normalize it onto the baseline's hardware.
+fn compare_phase(
+    phase: &BenchmarkPhase,
+    baseline: Option<&BenchmarkPhase>,
+    config: &BenchmarkConfig,
+    hardware_ratio: f64,
+) -> PhaseComparison {
+    let (duration_change_percent, duration_classification) =
+        match (phase.mean, baseline.and_then(|p| p.mean)) {
+            (Some(current), Some(previous)) if previous.as_secs_f64() != 0.0 => {
+                let current = Duration::from_secs_f64(current.as_secs_f64() * hardware_ratio);
+                let change_percent = percent_change(previous.as_secs_f64(), current.as_secs_f64());
+                let mut classification = classify_change(change_percent, config.noise_threshold);
+
+                // When we have a baseline confidence interval, only report a regression if the
+                // new mean additionally falls outside of it, so a single noisy baseline sample
+                // doesn't fail the run.
+                if classification == Classification::Regressed {
+                    if let (Some(ci_lower), Some(ci_upper)) =
+                        (baseline.and_then(|p| p.ci_lower), baseline.and_then(|p| p.ci_upper))
+                    {
+                        if current >= ci_lower && current <= ci_upper {
+                            classification = Classification::Unchanged;
+                        }
+                    }
+                }
+
+                (Some(change_percent), classification)
+            }
+            _ => (None, Classification::Unchanged),
+        };
+
+    PhaseComparison {
+        name: phase.name.clone(),
+        duration_change_percent,
+        duration_classification,
+    }
+}
+
+/// Returns the percentage change from `previous` to `current`.
+fn percent_change(previous: f64, current: f64) -> f64 {
+    (current - previous) / previous * 100.0
+}
+
+/// Classifies a percentage change as improved, regressed, or unchanged, treating anything within
+/// `noise_threshold` (a fraction, e.g. `0.05`) as unchanged.
+fn classify_change(change_percent: f64, noise_threshold: f64) -> Classification {
+    let threshold_percent = noise_threshold * 100.0;
+
+    if change_percent.abs() < threshold_percent {
+        Classification::Unchanged
+    } else if change_percent > 0.0 {
+        Classification::Regressed
+    } else {
+        Classification::Improved
+    }
+}
+
+/// The classification of a metric's change between a baseline and the current run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Classification {
+    Improved,
+    Regressed,
+    Unchanged,
+}
+
+/// The comparison of a single phase's duration against its baseline counterpart.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseComparison {
+    /// The name of the benchmark phase.
+    pub name: String,
+    /// The percentage change in mean duration relative to the baseline, if both runs have one.
+    pub duration_change_percent: Option<f64>,
+    /// The classification of the duration change.
+    pub duration_classification: Classification,
+}
+
+/// The comparison of a single benchmark against its baseline counterpart.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkComparison {
+    /// The name of the benchmark.
+    pub name: String,
+    /// The percentage change in bytecode size relative to the baseline, if both runs have one.
+    pub bytecode_size_change_percent: Option<f64>,
+    /// The classification of the bytecode size change.
+    pub bytecode_size_classification: Classification,
+    /// The comparison of each of the benchmark's phases.
+    pub phases: Vec<PhaseComparison>,
+}
+
+/// A full comparison of a set of benchmark results against a baseline.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparisonReport {
+    pub benchmarks: Vec<BenchmarkComparison>,
+}
+
+impl ComparisonReport {
+    /// Returns `true` if any benchmark's bytecode size or any phase's duration regressed beyond
+    /// the configured noise threshold.
+    pub fn has_regression(&self) -> bool {
+        self.benchmarks.iter().any(|benchmark| {
+            benchmark.bytecode_size_classification == Classification::Regressed
+                || benchmark
+                    .phases
+                    .iter()
+                    .any(|phase| phase.duration_classification == Classification::Regressed)
+        })
+    }
 }
 
 /// Benchmark metadata and phase-specific performance data.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Benchmark {
     /// The name of the benchmark.
     pub name: String,
@@ -32,6 +243,22 @@ pub struct Benchmark {
     pub phases: Vec<BenchmarkPhase>,
     /// The performance frames collected from the benchmark.
     pub frames: Arc<Mutex<Vec<BenchmarkFrame>>>,
+    /// Whether the benchmark's measured builds succeeded or failed.
+    pub status: BenchmarkStatus,
+}
+
+/// Whether a benchmark's `forc build` invocations succeeded or failed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BenchmarkStatus {
+    /// All measured builds completed successfully.
+    Success,
+    /// A build exited with a non-zero status, failed to spawn, or otherwise could not be measured.
+    Failed {
+        /// The process exit code, if the process ran and exited.
+        code: Option<i32>,
+        /// The last `STDERR_TAIL_LINES` lines of stderr from the failing build.
+        stderr_tail: Vec<String>,
+    },
 }
 
 impl Benchmark {
@@ -46,11 +273,46 @@ impl Benchmark {
             bytecode_size: None,
             phases: vec![],
             frames: Arc::new(Mutex::new(Vec::new())).into(),
+            status: BenchmarkStatus::Success,
+        }
+    }
+
+    /// Runs the benchmark according to `config`, performing `warm_up_runs` discarded builds
+    /// followed by `sample_size` measured builds, then aggregating the measured samples into
+    /// statistics stored on each `BenchmarkPhase`.
+    pub fn run(&mut self, epoch: &Instant, config: &BenchmarkConfig) {
+        for _ in 0..config.warm_up_runs {
+            self.clear_build_output();
+            let _ = self.run_once(epoch, config);
+        }
+
+        let mut samples = Vec::with_capacity(config.sample_size);
+        for _ in 0..config.sample_size {
+            self.clear_build_output();
+            let sample = self.run_once(epoch, config);
+
+            // Stop sampling a benchmark as soon as its build fails; repeating a broken build
+            // wastes time without producing any usable measurements.
+            let failed = matches!(sample.status, BenchmarkStatus::Failed { .. });
+            samples.push(sample);
+
+            if failed {
+                break;
+            }
         }
+
+        self.apply_samples(&samples, config);
     }
 
-    /// Runs the benchmark.
-    pub fn run(&mut self, epoch: &Instant) {
+    /// Removes the project's build output directory so each measured sample starts from a cold cache.
+    fn clear_build_output(&self) {
+        let _ = std::fs::remove_dir_all(self.path.join("out"));
+    }
+
+    /// Runs `forc build` once in the benchmark's directory and returns the raw measurements
+    /// collected for that single run. Never panics on a build failure; instead the returned
+    /// sample's `status` reports the exit code and a tail of stderr.
+    fn run_once(&self, epoch: &Instant, config: &BenchmarkConfig) -> RunSample {
         // Ensure the benchmark's path is a directory we can run `forc build` in
         assert!(
             self.verify_path(),
@@ -58,25 +320,39 @@ impl Benchmark {
             self.path.display()
         );
 
-        // Set the start time of the benchmark
-        self.start_time = Some(epoch.elapsed());
+        let start_time = Some(epoch.elapsed());
 
         // Spawn the `forc build` child command in the benchmark's directory
         // NOTE: stdin and stdout are piped so that we can use them to signal individual phases
-        let mut command = Command::new(
-            "/Users/georgiosdelkos/Documents/GitHub/Fuel/forked/sway/target/release/forc",
-        )
-        .arg("build")
-        .arg("--profile-phases")
-        .arg("--time-phases")
-        .arg("--log-level")
-        .arg("5")
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::null())
-        .current_dir(self.path.clone())
-        .spawn()
-        .unwrap();
+        let command = Command::new(&config.forc_path)
+            .arg("build")
+            .arg("--profile-phases")
+            .arg("--time-phases")
+            .arg("--log-level")
+            .arg("5")
+            .args(&config.build_args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .current_dir(self.path.clone())
+            .spawn();
+
+        let mut command = match command {
+            Ok(command) => command,
+            Err(err) => {
+                return RunSample {
+                    start_time,
+                    end_time: Some(epoch.elapsed()),
+                    bytecode_size: None,
+                    phases: vec![],
+                    frames: vec![],
+                    status: BenchmarkStatus::Failed {
+                        code: None,
+                        stderr_tail: vec![format!("failed to spawn \"{}\": {err}", config.forc_path.display())],
+                    },
+                };
+            }
+        };
 
         // Create an unbounded channel to send/receive line strings between the readline thread and the main thread
         let (readline_tx, readline_rx) = unbounded();
@@ -90,28 +366,115 @@ impl Benchmark {
         // Create a channel to send/receive STOP signals between the perf thread and the main thread
         let (stop_perf_tx, stop_perf_rx) = unbounded();
 
+        let frames = Arc::new(Mutex::new(Vec::new()));
+
         Self::spawn_perf_thread(
             epoch,
             pid,
             stop_perf_rx,
             stop_readline_rx.clone(),
-            self.frames.clone(),
+            frames.clone(),
         );
 
         // Spawn a thread to read lines from the command's stdout without blocking the main thread
         Self::spawn_readline_thread(&mut command, stop_readline_rx, readline_tx);
 
-        // Collect frames for each phase of the command
-        self.wait(
+        // Spawn a thread to retain the tail of the command's stderr, in case the build fails
+        let stderr_tail = Self::spawn_stderr_thread(&mut command);
+
+        let mut phases = Vec::new();
+        let mut bytecode_size = None;
+
+        // Collect frames for each phase of the command, until it exits
+        let exit_status = Self::wait(
             epoch,
             &mut command,
             &stop_readline_tx,
             &stop_perf_tx,
             &readline_rx,
+            &mut phases,
+            &mut bytecode_size,
         );
 
-        // Set the end time of the benchmark
-        self.end_time = Some(epoch.elapsed());
+        let end_time = Some(epoch.elapsed());
+        let frames = frames.lock().unwrap().clone();
+
+        let status = match exit_status {
+            Ok(status) if status.success() => BenchmarkStatus::Success,
+            Ok(status) => BenchmarkStatus::Failed {
+                code: status.code(),
+                stderr_tail: stderr_tail.lock().unwrap().iter().cloned().collect(),
+            },
+            Err(err) => BenchmarkStatus::Failed {
+                code: None,
+                stderr_tail: vec![format!("failed to wait on child process: {err}")],
+            },
+        };
+
+        RunSample {
+            start_time,
+            end_time,
+            bytecode_size,
+            phases,
+            frames,
+            status,
+        }
+    }
+
+    /// Aggregates a set of measured `samples` into this benchmark's reported phases, bytecode
+    /// size, frames, and status.
+    fn apply_samples(&mut self, samples: &[RunSample], config: &BenchmarkConfig) {
+        let Some(last) = samples.last() else {
+            return;
+        };
+
+        self.start_time = samples.first().and_then(|sample| sample.start_time);
+        self.end_time = last.end_time;
+        *self.frames.lock().unwrap() = last.frames.clone();
+
+        if let Some(failure) = samples
+            .iter()
+            .find(|sample| matches!(sample.status, BenchmarkStatus::Failed { .. }))
+        {
+            self.status = failure.status.clone();
+            return;
+        }
+
+        self.status = BenchmarkStatus::Success;
+
+        let bytecode_sizes: Vec<f64> = samples
+            .iter()
+            .filter_map(|sample| sample.bytecode_size)
+            .map(|size| size as f64)
+            .collect();
+
+        if !bytecode_sizes.is_empty() {
+            self.bytecode_size = Some(stats::mean(&bytecode_sizes).round() as usize);
+        }
+
+        self.phases = last
+            .phases
+            .iter()
+            .map(|phase| {
+                let durations: Vec<f64> = samples
+                    .iter()
+                    .filter_map(|sample| sample.phases.iter().find(|p| p.name == phase.name))
+                    .filter_map(|p| match (p.start_time, p.end_time) {
+                        (Some(start), Some(end)) => Some(end.saturating_sub(start).as_secs_f64()),
+                        _ => None,
+                    })
+                    .collect();
+
+                BenchmarkPhase::from_samples(
+                    phase.name.clone(),
+                    phase.start_time,
+                    phase.end_time,
+                    &durations,
+                    &last.frames,
+                    config,
+                )
+            })
+            .collect();
     }
 
     pub fn verify_path(&self) -> bool {
@@ -140,18 +503,23 @@ impl Benchmark {
         stop_readline_rx: Receiver<()>,
         readline_tx: Sender<String>,
     ) {
-        let command_stdout = command.stdout.take().unwrap();
+        let Some(command_stdout) = command.stdout.take() else {
+            return;
+        };
 
         std::thread::spawn(move || {
             // Wrap the stdout of the child command in a BufReader and move it into the readline thread
             let command_stdout = std::io::BufReader::new(command_stdout);
 
             for line in command_stdout.lines() {
-                let line = line.unwrap().trim_end().to_string();
+                // Stop looping and allow the readline thread to exit on an I/O error
+                let Ok(line) = line else {
+                    break;
+                };
 
                 // Attempt to send the line to the main thread, or stop looping and allow
                 // the readline thread to exit if it fails
-                if readline_tx.send(line).is_err() {
+                if readline_tx.send(line.trim_end().to_string()).is_err() {
                     break;
                 }
 
@@ -163,27 +531,63 @@ impl Benchmark {
         });
     }
 
+    /// Spawns a thread that retains the last `STDERR_TAIL_LINES` lines of the command's stderr,
+    /// so a failing build's error output can be reported without buffering it all in memory.
+    fn spawn_stderr_thread(command: &mut Child) -> Arc<Mutex<VecDeque<String>>> {
+        let tail = Arc::new(Mutex::new(VecDeque::with_capacity(STDERR_TAIL_LINES)));
+
+        let Some(command_stderr) = command.stderr.take() else {
+            return tail;
+        };
+
+        let thread_tail = tail.clone();
+
+        std::thread::spawn(move || {
+            let command_stderr = std::io::BufReader::new(command_stderr);
+
+            for line in command_stderr.lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+
+                let mut tail = thread_tail.lock().unwrap();
+                if tail.len() == STDERR_TAIL_LINES {
+                    tail.pop_front();
+                }
+                tail.push_back(line);
+            }
+        });
+
+        tail
+    }
+
+    /// Polls `command` for phase markers and bytecode size on its stdout until it exits,
+    /// returning its exit status.
     fn wait(
-        &mut self,
         epoch: &Instant,
         command: &mut Child,
         stop_readline_tx: &Sender<()>,
         stop_perf_tx: &Sender<()>,
         readline_rx: &Receiver<String>,
-    ) {
+        phases: &mut Vec<BenchmarkPhase>,
+        bytecode_size: &mut Option<usize>,
+    ) -> std::io::Result<std::process::ExitStatus> {
         // Loop until the command has exited
         loop {
-            // If the command has exited, tell the readline thread to stop and stop looping
-            if command.try_wait().unwrap().is_some() {
-                if stop_readline_tx.send(()).is_err() {
-                    break;
+            match command.try_wait() {
+                // The command hasn't exited yet; keep polling its output below
+                Ok(None) => {}
+                // The command has exited; tell the readline and perf threads to stop and return its status
+                Ok(Some(status)) => {
+                    let _ = stop_readline_tx.send(());
+                    let _ = stop_perf_tx.send(());
+                    return Ok(status);
                 }
-
-                if stop_perf_tx.send(()).is_err() {
-                    break;
+                Err(err) => {
+                    let _ = stop_readline_tx.send(());
+                    let _ = stop_perf_tx.send(());
+                    return Err(err);
                 }
-
-                break;
             }
 
             // Attempt to receive a line from the readline thread
@@ -198,41 +602,25 @@ impl Benchmark {
                 let name = line.trim_start_matches("/forc-perf start ").trim_end();
 
                 // Add the phase to the current benchmark
-                self.phases.push(BenchmarkPhase {
-                    name: name.into(),
-                    start_time: Some(epoch.elapsed()),
-                    end_time: None,
-                });
+                phases.push(BenchmarkPhase::new(name, Some(epoch.elapsed())));
             } else if line.starts_with("/forc-perf stop ") {
                 // Get the name of the phase from the end of the line
                 let name = line.trim_start_matches("/forc-perf stop ").trim_end();
 
-                // Get the current benchmark phase
-                let phase = self
-                    .phases
-                    .iter_mut()
-                    .rev()
-                    .find(|phase| name == phase.name)
-                    .unwrap();
-
-                // Ensure the received name matches the name of the current phase
-                assert!(
-                    name == phase.name,
-                    "Received phase name \"{}\" does not match current phase name \"{}\"",
-                    name,
-                    phase.name,
-                );
+                // Get the current benchmark phase, ignoring the line if it doesn't match one we started
+                let Some(phase) = phases.iter_mut().rev().find(|phase| name == phase.name) else {
+                    continue;
+                };
 
                 // Set the end time of the benchmark
                 phase.end_time = Some(epoch.elapsed());
             } else if line.starts_with("/forc-perf size ") {
                 // Parse the size of the bytecode compiled for the benchmark code from the end of the line
-                self.bytecode_size = Some(
-                    line.trim_start_matches("/forc-perf size ")
-                        .trim_end()
-                        .parse()
-                        .unwrap()
-                );
+                *bytecode_size = line
+                    .trim_start_matches("/forc-perf size ")
+                    .trim_end()
+                    .parse()
+                    .ok();
             }
         }
     }
@@ -277,8 +665,9 @@ impl Benchmark {
                 break;
             }
 
+            // If the process has already exited, stop looping and allow the perf thread to exit
             let Some(process) = system.process(pid) else {
-                panic!("Failed to find process with pid {pid}");
+                break;
             };
 
             let cpu_usage = process.cpu_usage() / num_cpus as f32;
@@ -308,7 +697,7 @@ impl Benchmark {
 }
 
 /// A named collection of performance frames representing a single phase of a benchmark.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BenchmarkPhase {
     /// The name of the benchmark phase.
     pub name: String,
@@ -316,6 +705,148 @@ pub struct BenchmarkPhase {
     pub start_time: Option<Duration>,
     /// The end time of the benchmark phase.
     pub end_time: Option<Duration>,
+    /// The mean duration of the phase across all measured samples, excluding severe outliers.
+    pub mean: Option<Duration>,
+    /// The sample standard deviation of the phase's duration across all measured samples.
+    pub std_dev: Option<Duration>,
+    /// The median duration of the phase across all measured samples.
+    pub median: Option<Duration>,
+    /// The lower bound of the bootstrap confidence interval for the mean duration.
+    pub ci_lower: Option<Duration>,
+    /// The upper bound of the bootstrap confidence interval for the mean duration.
+    pub ci_upper: Option<Duration>,
+    /// The number of samples the above statistics were computed from, after excluding severe outliers.
+    pub sample_count: usize,
+    /// The peak process memory usage (in bytes) while this phase was active.
+    pub peak_memory_usage: Option<u64>,
+    /// The mean process CPU usage while this phase was active.
+    pub mean_cpu_usage: Option<f32>,
+    /// The peak process CPU usage while this phase was active.
+    pub peak_cpu_usage: Option<f32>,
+    /// The total bytes read from disk while this phase was active.
+    pub bytes_read: Option<u64>,
+    /// The total bytes written to disk while this phase was active.
+    pub bytes_written: Option<u64>,
+}
+
+impl BenchmarkPhase {
+    /// Creates a new phase with no statistics, used while a phase is still being measured.
+    fn new(name: &str, start_time: Option<Duration>) -> Self {
+        Self {
+            name: name.into(),
+            start_time,
+            end_time: None,
+            mean: None,
+            std_dev: None,
+            median: None,
+            ci_lower: None,
+            ci_upper: None,
+            sample_count: 0,
+            peak_memory_usage: None,
+            mean_cpu_usage: None,
+            peak_cpu_usage: None,
+            bytes_read: None,
+            bytes_written: None,
+        }
+    }
+
+    /// Builds a phase's reported statistics from its per-sample `durations` (in seconds),
+    /// excluding severe outliers from the computed mean, standard deviation, median, and
+    /// confidence interval, and computes its resource usage from the `frames` captured while
+    /// the phase (spanning `start_time` to `end_time`) was active.
+    fn from_samples(
+        name: String,
+        start_time: Option<Duration>,
+        end_time: Option<Duration>,
+        durations: &[f64],
+        frames: &[BenchmarkFrame],
+        config: &BenchmarkConfig,
+    ) -> Self {
+        let filtered: Vec<f64> = durations
+            .iter()
+            .copied()
+            .filter(|duration| stats::classify_outlier(*duration, durations) != stats::OutlierSeverity::Severe)
+            .collect();
+
+        let sample_count = filtered.len();
+
+        let (mean, std_dev, median, ci_lower, ci_upper) = if filtered.is_empty() {
+            (None, None, None, None, None)
+        } else {
+            let ci = stats::bootstrap_confidence_interval(&filtered, config.confidence_level, config.nresamples);
+
+            (
+                Some(Duration::from_secs_f64(stats::mean(&filtered))),
+                Some(Duration::from_secs_f64(stats::std_dev(&filtered))),
+                Some(Duration::from_secs_f64(stats::median(&filtered))),
+                Some(Duration::from_secs_f64(ci.lower)),
+                Some(Duration::from_secs_f64(ci.upper)),
+            )
+        };
+
+        let phase_frames: Vec<&BenchmarkFrame> = phase_frames(frames, start_time, end_time).collect();
+
+        let peak_memory_usage = phase_frames.iter().map(|frame| frame.memory_usage).max();
+
+        let mean_cpu_usage = if phase_frames.is_empty() {
+            None
+        } else {
+            Some(phase_frames.iter().map(|frame| frame.cpu_usage).sum::<f32>() / phase_frames.len() as f32)
+        };
+
+        let peak_cpu_usage = phase_frames
+            .iter()
+            .map(|frame| frame.cpu_usage)
+            .fold(None, |peak: Option<f32>, usage| Some(peak.map_or(usage, |peak| peak.max(usage))));
+
+        let (bytes_read, bytes_written) = if phase_frames.is_empty() {
+            (None, None)
+        } else {
+            (
+                Some(phase_frames.iter().map(|frame| frame.disk_read_bytes).sum()),
+                Some(phase_frames.iter().map(|frame| frame.disk_written_bytes).sum()),
+            )
+        };
+
+        Self {
+            name,
+            start_time,
+            end_time,
+            mean,
+            std_dev,
+            median,
+            ci_lower,
+            ci_upper,
+            sample_count,
+            peak_memory_usage,
+            mean_cpu_usage,
+            peak_cpu_usage,
+            bytes_read,
+            bytes_written,
+        }
+    }
+}
+
+/// Returns the frames captured while a phase spanning `start_time` to `end_time` was active.
+fn phase_frames(
+    frames: &[BenchmarkFrame],
+    start_time: Option<Duration>,
+    end_time: Option<Duration>,
+) -> impl Iterator<Item = &BenchmarkFrame> {
+    frames.iter().filter(move |frame| match (start_time, end_time) {
+        (Some(start), Some(end)) => frame.timestamp >= start && frame.timestamp <= end,
+        _ => false,
+    })
+}
+
+/// The raw measurements collected from a single, unaggregated run of a benchmark.
+struct RunSample {
+    start_time: Option<Duration>,
+    end_time: Option<Duration>,
+    bytecode_size: Option<usize>,
+    phases: Vec<BenchmarkPhase>,
+    frames: Vec<BenchmarkFrame>,
+    status: BenchmarkStatus,
 }
 
 impl BenchmarkFrame {
@@ -324,7 +855,7 @@ impl BenchmarkFrame {
 }
 
 /// A single frame of performance information for a benchmark phase.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BenchmarkFrame {
     /// The time that the frame was captured.
     pub timestamp: Duration,
@@ -1,8 +1,10 @@
 use crate::types::{Benchmark, SystemSpecs};
 
 /// Collect all profiling targets in the given directory and return a map of the target name to the path canonical path.
+/// When `filter` is given, only benchmarks whose name contains it as a substring are returned.
 pub fn generate_benchmarks<P: AsRef<std::path::Path>>(
     path: P,
+    filter: Option<&str>,
 ) -> Result<Vec<Benchmark>, Box<dyn std::error::Error>> {
     let path = path.as_ref();
 
@@ -18,6 +20,10 @@ pub fn generate_benchmarks<P: AsRef<std::path::Path>>(
         let canonical_path = std::fs::canonicalize(entry_path)?;
 
         if let Some(name) = canonical_path.file_name().and_then(|n| n.to_str()) {
+            if filter.is_some_and(|filter| !name.contains(filter)) {
+                continue;
+            }
+
             let benchmark = Benchmark::new(&name.to_string(), canonical_path.clone());
             if benchmark.verify_path() {
                 targets.push(benchmark);